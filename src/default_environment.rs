@@ -0,0 +1,11 @@
+//! Builds the environment new REPL sessions and file runs start from.
+
+use crate::model::Env;
+
+/// Builds a fresh environment with no surrounding parent scope.
+///
+/// Intrinsics are registered here as the interpreter grows; for now this
+/// just hands back an empty, bindable scope.
+pub fn default_env() -> Env {
+    Env::new()
+}
@@ -0,0 +1,159 @@
+//! Tree-walking evaluator.
+
+use crate::model::{Env, RuntimeError, Value};
+use crate::utils::describe;
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// Evaluates a single expression against `env`.
+pub fn eval(env: Rc<RefCell<Env>>, expr: &Value) -> Result<Value, RuntimeError> {
+    match expr {
+        Value::Symbol(name) => env
+            .borrow()
+            .get(name)
+            .ok_or_else(|| RuntimeError::new(format!("unbound symbol: {}", name))),
+        Value::List(items) => eval_list(env, items),
+        other => Ok(other.clone()),
+    }
+}
+
+fn eval_list(env: Rc<RefCell<Env>>, items: &[Value]) -> Result<Value, RuntimeError> {
+    if items.is_empty() {
+        return Ok(Value::NIL);
+    }
+
+    match &items[0] {
+        Value::Symbol(s) if s == "define" => {
+            let name = match &items[1] {
+                Value::Symbol(s) => s.clone(),
+                _ => return Err(RuntimeError::new("define: expected a symbol")),
+            };
+            let value = eval(env.clone(), &items[2])?;
+            env.borrow_mut().define(&name, value.clone());
+            Ok(value)
+        }
+        Value::Symbol(s) if s == "quote" => Ok(items[1].clone()),
+        Value::Symbol(s) if s == "if" => {
+            let cond = eval(env.clone(), &items[1])?;
+            if is_truthy(&cond) {
+                eval(env, &items[2])
+            } else if let Some(else_branch) = items.get(3) {
+                eval(env, else_branch)
+            } else {
+                Ok(Value::NIL)
+            }
+        }
+        first => {
+            let head = eval(env.clone(), first)?;
+            let args = items[1..]
+                .iter()
+                .map(|a| eval(env.clone(), a))
+                .collect::<Result<Vec<_>, _>>()?;
+            apply(&head, &args)
+        }
+    }
+}
+
+fn is_truthy(value: &Value) -> bool {
+    !matches!(value, Value::Bool(false) | Value::NIL)
+}
+
+fn apply(head: &Value, args: &[Value]) -> Result<Value, RuntimeError> {
+    match head {
+        Value::Symbol(s) => Err(RuntimeError::new(format!("not callable: {}", s))),
+        other => Err(RuntimeError::new(format!(
+            "value of type {} is not callable (called with {} args)",
+            other.type_name(),
+            args.len()
+        ))),
+    }
+}
+
+/// Evaluates a sequence of top-level expressions against `env`, returning
+/// the value of the last one.
+pub fn eval_block(
+    env: Rc<RefCell<Env>>,
+    exprs: impl Iterator<Item = Value>,
+) -> Result<Value, RuntimeError> {
+    eval_block_verbose(env, exprs, false)
+}
+
+/// Like [`eval_block`], but when `verbose` is true, prints the read-phase
+/// AST of each form (via `Value`'s `Debug` impl) before evaluating it, and
+/// the eval-phase result (with its type) after. Useful for debugging
+/// macro expansion and parser behavior: what the reader produced versus
+/// what evaluation yielded.
+pub fn eval_block_verbose(
+    env: Rc<RefCell<Env>>,
+    exprs: impl Iterator<Item = Value>,
+    verbose: bool,
+) -> Result<Value, RuntimeError> {
+    eval_each(env, exprs.map(|expr| (expr, None)), verbose)
+}
+
+/// Like [`eval_block_verbose`], but each expression also carries the
+/// byte-offset span it was parsed from; on failure, that span is
+/// attached to the returned [`RuntimeError`] so callers (the REPL, the
+/// file runner) can render a caret diagnostic pointing back at the
+/// originating form. This, [`eval_block`] and [`eval_block_verbose`] all
+/// share one evaluation loop, so read/eval/verbose-print behavior can't
+/// drift between them.
+pub fn eval_block_spanned_verbose(
+    env: Rc<RefCell<Env>>,
+    exprs: impl Iterator<Item = (Value, Range<usize>)>,
+    verbose: bool,
+) -> Result<Value, RuntimeError> {
+    eval_each(env, exprs.map(|(expr, span)| (expr, Some(span))), verbose)
+}
+
+fn eval_each(
+    env: Rc<RefCell<Env>>,
+    exprs: impl Iterator<Item = (Value, Option<Range<usize>>)>,
+    verbose: bool,
+) -> Result<Value, RuntimeError> {
+    let mut result = Value::NIL;
+    for (expr, span) in exprs {
+        if verbose {
+            println!("read: {:?}", expr);
+        }
+        result = eval(env.clone(), &expr).map_err(|e| match span {
+            Some(span) => e.with_span(span),
+            None => e,
+        })?;
+        if verbose {
+            println!("eval: {}", describe(&result));
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    fn exprs(source: &str) -> Vec<Value> {
+        parse(source).collect::<Result<_, _>>().unwrap()
+    }
+
+    #[test]
+    fn verbose_mode_does_not_change_the_returned_value() {
+        let env = Rc::new(RefCell::new(Env::new()));
+        let quiet = eval_block(env.clone(), exprs("(define x 1) (define y 2) (if x y 0)").into_iter());
+        let loud = eval_block_verbose(env, exprs("(define x 1) (define y 2) (if x y 0)").into_iter(), true);
+        assert_eq!(quiet, Ok(Value::Int(2)));
+        assert_eq!(loud, Ok(Value::Int(2)));
+    }
+
+    #[test]
+    fn verbose_mode_still_attaches_the_span_of_a_failing_form() {
+        let env = Rc::new(RefCell::new(Env::new()));
+        let source = "undefined_symbol";
+        let spanned: Vec<_> = crate::parse_spanned(source)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let err = eval_block_spanned_verbose(env, spanned.into_iter(), true).unwrap_err();
+        assert_eq!(err.span, Some(0..source.len()));
+    }
+}
@@ -3,37 +3,108 @@
 mod default_environment;
 mod interpreter;
 mod parser;
+#[cfg(feature = "repl")]
+mod repl;
 
 pub use default_environment::default_env;
-pub use interpreter::{eval, eval_block};
-pub use parser::{parse, ParseError};
+pub use interpreter::{eval, eval_block, eval_block_spanned_verbose, eval_block_verbose};
+pub use parser::{parse, parse_spanned, ParseError};
+#[cfg(feature = "repl")]
+pub use repl::{start_repl, ReplConfig};
 
 pub mod model;
 pub mod utils;
 #[macro_use]
 pub mod macros;
 
-use model::Env;
-use std::io::{self, prelude::*};
-use std::{cell::RefCell, rc::Rc};
-
-// 🦀 I am all over this project!
-/// Starts a REPL prompt at stdin/stdout. **This will block the current thread.**
-pub fn start_repl(env: Option<Env>) {
-    let env_rc = Rc::new(RefCell::new(env.unwrap_or_else(default_env)));
-
-    print!("> ");
-    io::stdout().flush().unwrap();
-    for line in io::stdin().lock().lines() {
-        match eval_block(env_rc.clone(), parse(&line.unwrap()).filter_map(|a| a.ok())) {
-            Ok(val) => println!("{}", val),
-            Err(e) => println!("{}", e),
-        };
-
-        print!("> ");
-        io::stdout().flush().unwrap();
+use model::{Env, RuntimeError, Value};
+use std::cell::RefCell;
+use std::fs;
+use std::path::Path;
+use std::rc::Rc;
+
+/// Parses and evaluates `source` against `env`, returning the value of
+/// the last top-level form. Errors carry the byte-offset span of the
+/// form that caused them, so callers can render a caret diagnostic with
+/// [`utils::format_caret`].
+pub fn run_str(source: &str, env: Rc<RefCell<Env>>) -> Result<Value, RuntimeError> {
+    run_str_verbose(source, env, false)
+}
+
+/// Like [`run_str`], but when `verbose` is true, prints the read-phase AST
+/// and eval-phase result (with its type) for each top-level form.
+pub fn run_str_verbose(
+    source: &str,
+    env: Rc<RefCell<Env>>,
+    verbose: bool,
+) -> Result<Value, RuntimeError> {
+    let exprs: Vec<_> = parse_spanned(source)
+        .collect::<Result<_, _>>()
+        .map_err(|e: ParseError| RuntimeError::new(e.to_string()).with_span(e.span()))?;
+
+    eval_block_spanned_verbose(env, exprs.into_iter(), verbose)
+}
+
+/// Reads `path`, then parses and evaluates its contents in order against
+/// `env`, returning the value of the last top-level form. This is how a
+/// prelude or script file gets loaded before a REPL or `-c` expression
+/// runs against the same environment.
+pub fn run_file(path: impl AsRef<Path>, env: Rc<RefCell<Env>>) -> Result<Value, RuntimeError> {
+    run_file_verbose(path, env, false)
+}
+
+/// Like [`run_file`], but when `verbose` is true, prints the read-phase
+/// AST and eval-phase result (with its type) for each top-level form.
+pub fn run_file_verbose(
+    path: impl AsRef<Path>,
+    env: Rc<RefCell<Env>>,
+    verbose: bool,
+) -> Result<Value, RuntimeError> {
+    let source = fs::read_to_string(path.as_ref())
+        .map_err(|e| RuntimeError::new(format!("{}: {}", path.as_ref().display(), e)))?;
+    run_str_verbose(&source, env, verbose)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env() -> Rc<RefCell<Env>> {
+        Rc::new(RefCell::new(default_env()))
     }
 
-    // Properly go to the next line after quitting
-    println!();
+    #[test]
+    fn run_str_returns_the_value_of_the_last_form() {
+        let result = run_str("(define x 1) (define y 2) y", env());
+        assert_eq!(result, Ok(Value::Int(2)));
+    }
+
+    #[test]
+    fn run_str_reports_a_parse_error_with_its_span() {
+        let err = run_str("(foo", env()).unwrap_err();
+        assert_eq!(err.span, Some(4..4));
+    }
+
+    #[test]
+    fn run_str_reports_a_runtime_error() {
+        let err = run_str("undefined_symbol", env()).unwrap_err();
+        assert_eq!(err.msg, "unbound symbol: undefined_symbol");
+    }
+
+    #[test]
+    fn run_file_loads_and_evaluates_a_script() {
+        let path = std::env::temp_dir().join("rust_lisp_run_file_test.lisp");
+        fs::write(&path, "(define x 41) (define y (quote ignored)) 42").unwrap();
+
+        let result = run_file(&path, env());
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(result, Ok(Value::Int(42)));
+    }
+
+    #[test]
+    fn run_file_reports_an_io_error_for_a_missing_file() {
+        let err = run_file("/no/such/file.lisp", env()).unwrap_err();
+        assert!(err.msg.contains("/no/such/file.lisp"));
+    }
 }
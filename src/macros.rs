@@ -0,0 +1,10 @@
+//! Convenience macros for building [`Value`](crate::model::Value)s by hand,
+//! mainly useful when registering intrinsics in [`crate::default_environment`].
+
+/// Builds a `Value::List` from a sequence of values, e.g. `lisp_list![Value::Int(1), Value::Int(2)]`.
+#[macro_export]
+macro_rules! lisp_list {
+    ($($item:expr),* $(,)?) => {
+        $crate::model::Value::List(vec![$($item),*])
+    };
+}
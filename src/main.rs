@@ -0,0 +1,82 @@
+//! CLI front-end: loads file arguments in order, optionally evaluates a
+//! `-c` expression against the same environment, then drops into the
+//! REPL unless `--no-repl` is given.
+
+use clap::Parser;
+use rust_lisp::default_env;
+use rust_lisp::utils::format_caret;
+use rust_lisp::{run_file_verbose, run_str_verbose};
+use std::cell::RefCell;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::rc::Rc;
+
+#[derive(Parser)]
+#[command(name = "rust_lisp", about = "A small embeddable Lisp interpreter")]
+struct Cli {
+    /// Source files to load, in order, before anything else runs.
+    files: Vec<PathBuf>,
+
+    /// Evaluate <expr> directly, against the environment left by any loaded files.
+    #[arg(short = 'c', value_name = "expr")]
+    expr: Option<String>,
+
+    /// Don't start the REPL after loading files / evaluating -c.
+    #[arg(long)]
+    no_repl: bool,
+
+    /// Print the parsed AST and typed result of every top-level form.
+    #[arg(long)]
+    verbose: bool,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let env = Rc::new(RefCell::new(default_env()));
+
+    for file in &cli.files {
+        if let Err(e) = run_file_verbose(file, env.clone(), cli.verbose) {
+            if let (Some(span), Ok(source)) = (e.span.clone(), fs::read_to_string(file)) {
+                eprintln!("{}", format_caret(&source, span));
+            }
+            eprintln!("{}: {}", file.display(), e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if let Some(expr) = &cli.expr {
+        match run_str_verbose(expr, env.clone(), cli.verbose) {
+            Ok(val) => println!("{}", val),
+            Err(e) => {
+                if let Some(span) = e.span.clone() {
+                    eprintln!("{}", format_caret(expr, span));
+                }
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if cli.no_repl {
+        return ExitCode::SUCCESS;
+    }
+
+    #[cfg(feature = "repl")]
+    {
+        let env = Rc::try_unwrap(env)
+            .map(RefCell::into_inner)
+            .unwrap_or_else(|_| default_env());
+        rust_lisp::start_repl(Some(rust_lisp::ReplConfig {
+            env: Some(env),
+            verbose: cli.verbose,
+            ..Default::default()
+        }));
+    }
+    #[cfg(not(feature = "repl"))]
+    {
+        eprintln!("REPL support was not compiled in (missing the `repl` feature)");
+    }
+
+    ExitCode::SUCCESS
+}
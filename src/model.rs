@@ -0,0 +1,147 @@
+//! Core data model shared by the parser, interpreter and REPL.
+//!
+//! `Value` doubles as both the parsed expression tree and the runtime
+//! representation produced by evaluation; this interpreter has no
+//! separate AST type.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// A Lisp value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+    String(String),
+    Symbol(String),
+    Bool(bool),
+    List(Vec<Value>),
+    NIL,
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Float(n) => write!(f, "{}", n),
+            Value::String(s) => write!(f, "{:?}", s),
+            Value::Symbol(s) => write!(f, "{}", s),
+            Value::Bool(b) => write!(f, "{}", if *b { "#t" } else { "#f" }),
+            Value::List(items) => {
+                write!(f, "(")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, ")")
+            }
+            Value::NIL => write!(f, "nil"),
+        }
+    }
+}
+
+impl Value {
+    /// A short name for the value's type, used by diagnostics and the
+    /// REPL's verbose mode.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Int(_) => "int",
+            Value::Float(_) => "float",
+            Value::String(_) => "string",
+            Value::Symbol(_) => "symbol",
+            Value::Bool(_) => "bool",
+            Value::List(_) => "list",
+            Value::NIL => "nil",
+        }
+    }
+}
+
+/// A parsed top-level expression. This interpreter reuses `Value` as its
+/// expression representation, so `Expr` is just an alias for clarity at
+/// parser/interpreter call sites.
+pub type Expr = Value;
+
+/// A lexical environment: a chain of scopes mapping symbol names to
+/// values, used for variable bindings.
+#[derive(Debug, Clone)]
+pub struct Env {
+    parent: Option<Rc<RefCell<Env>>>,
+    vars: HashMap<String, Value>,
+}
+
+impl Env {
+    /// Creates an empty, parentless environment.
+    pub fn new() -> Self {
+        Env {
+            parent: None,
+            vars: HashMap::new(),
+        }
+    }
+
+    /// Creates an empty environment scoped inside `parent`.
+    pub fn extend(parent: Rc<RefCell<Env>>) -> Self {
+        Env {
+            parent: Some(parent),
+            vars: HashMap::new(),
+        }
+    }
+
+    /// Binds `name` to `value` in this scope, shadowing any binding of
+    /// the same name in a parent scope.
+    pub fn define(&mut self, name: &str, value: Value) {
+        self.vars.insert(name.to_string(), value);
+    }
+
+    /// Looks up `name`, walking up through parent scopes if needed.
+    pub fn get(&self, name: &str) -> Option<Value> {
+        if let Some(value) = self.vars.get(name) {
+            return Some(value.clone());
+        }
+        self.parent.as_ref().and_then(|p| p.borrow().get(name))
+    }
+}
+
+impl Default for Env {
+    fn default() -> Self {
+        Env::new()
+    }
+}
+
+/// An error raised while evaluating a parsed expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeError {
+    pub msg: String,
+    /// Byte-offset span of the top-level form being evaluated, when
+    /// known. `eval` itself has no notion of spans (`Value` doesn't
+    /// carry them), so this is filled in by callers like [`crate::run_str`]
+    /// that still have the span the form was parsed from.
+    pub span: Option<Range<usize>>,
+}
+
+impl RuntimeError {
+    pub fn new(msg: impl Into<String>) -> Self {
+        RuntimeError {
+            msg: msg.into(),
+            span: None,
+        }
+    }
+
+    /// Attaches `span` to this error, overwriting any span it already had.
+    pub fn with_span(mut self, span: Range<usize>) -> Self {
+        self.span = Some(span);
+        self
+    }
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.msg)
+    }
+}
+
+impl std::error::Error for RuntimeError {}
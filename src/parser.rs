@@ -0,0 +1,245 @@
+//! Tokenizer and recursive-descent parser turning Lisp source text into a
+//! stream of [`Value`] expressions.
+
+use crate::model::Value;
+use std::fmt;
+use std::ops::Range;
+
+/// An error raised while parsing source text into expressions.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// A token was found where it didn't belong (e.g. a stray `)`).
+    UnexpectedToken { found: String, span: Range<usize> },
+    /// Source ended while a list or string was still open.
+    UnexpectedEof { span: Range<usize> },
+}
+
+impl ParseError {
+    /// Whether this error means the input simply ran out before an open
+    /// list or string was closed, as opposed to being malformed. Callers
+    /// like the REPL use this to decide whether to keep reading more
+    /// lines rather than reporting a hard failure.
+    pub fn is_incomplete(&self) -> bool {
+        matches!(self, ParseError::UnexpectedEof { .. })
+    }
+
+    /// The byte-offset span into the source this error refers to.
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            ParseError::UnexpectedToken { span, .. } => span.clone(),
+            ParseError::UnexpectedEof { span } => span.clone(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedToken { found, .. } => write!(f, "unexpected token: {}", found),
+            ParseError::UnexpectedEof { .. } => write!(f, "unexpected end of input"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+struct Token {
+    text: String,
+    span: Range<usize>,
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' | ')' => {
+                chars.next();
+                tokens.push(Token {
+                    text: c.to_string(),
+                    span: start..start + c.len_utf8(),
+                });
+            }
+            '"' => {
+                chars.next();
+                let mut end = start + 1;
+                let mut closed = false;
+                for (i, c) in chars.by_ref() {
+                    end = i + c.len_utf8();
+                    if c == '"' {
+                        closed = true;
+                        break;
+                    }
+                }
+                if !closed {
+                    // The string never hit a closing quote: source ran
+                    // out while still inside it.
+                    return Err(ParseError::UnexpectedEof { span: start..end });
+                }
+                tokens.push(Token {
+                    text: source[start..end].to_string(),
+                    span: start..end,
+                });
+            }
+            _ => {
+                let mut end = start;
+                while let Some(&(i, c)) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                tokens.push(Token {
+                    text: source[start..end].to_string(),
+                    span: start..end,
+                });
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn parse_atom(tok: &str) -> Value {
+    if let Ok(n) = tok.parse::<i64>() {
+        Value::Int(n)
+    } else if let Ok(n) = tok.parse::<f64>() {
+        Value::Float(n)
+    } else if tok == "#t" {
+        Value::Bool(true)
+    } else if tok == "#f" {
+        Value::Bool(false)
+    } else if tok.starts_with('"') && tok.ends_with('"') && tok.len() >= 2 {
+        Value::String(tok[1..tok.len() - 1].to_string())
+    } else {
+        Value::Symbol(tok.to_string())
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    source_len: usize,
+}
+
+impl Parser {
+    fn eof_span(&self) -> Range<usize> {
+        let end = self.tokens.last().map(|t| t.span.end).unwrap_or(self.source_len);
+        end..self.source_len.max(end)
+    }
+
+    fn next_expr(&mut self) -> Result<Option<(Value, Range<usize>)>, ParseError> {
+        if self.pos >= self.tokens.len() {
+            return Ok(None);
+        }
+        Ok(Some(self.parse_expr()?))
+    }
+
+    fn parse_expr(&mut self) -> Result<(Value, Range<usize>), ParseError> {
+        let Some(tok) = self.tokens.get(self.pos) else {
+            return Err(ParseError::UnexpectedEof {
+                span: self.eof_span(),
+            });
+        };
+
+        match tok.text.as_str() {
+            "(" => {
+                let start = tok.span.start;
+                self.pos += 1;
+                let mut items = Vec::new();
+                loop {
+                    match self.tokens.get(self.pos) {
+                        None => {
+                            return Err(ParseError::UnexpectedEof {
+                                span: self.eof_span(),
+                            })
+                        }
+                        Some(t) if t.text == ")" => {
+                            let end = t.span.end;
+                            self.pos += 1;
+                            return Ok((Value::List(items), start..end));
+                        }
+                        _ => items.push(self.parse_expr()?.0),
+                    }
+                }
+            }
+            ")" => Err(ParseError::UnexpectedToken {
+                found: tok.text.clone(),
+                span: tok.span.clone(),
+            }),
+            _ => {
+                let value = parse_atom(&tok.text);
+                let span = tok.span.clone();
+                self.pos += 1;
+                Ok((value, span))
+            }
+        }
+    }
+}
+
+/// Parses `source` into a lazy stream of top-level expressions.
+pub fn parse(source: &str) -> impl Iterator<Item = Result<Value, ParseError>> {
+    parse_spanned(source).map(|r| r.map(|(value, _)| value))
+}
+
+/// Like [`parse`], but also yields each expression's byte-offset span in
+/// `source`, for diagnostics that need to point back at the source text.
+pub fn parse_spanned(source: &str) -> impl Iterator<Item = Result<(Value, Range<usize>), ParseError>> {
+    let source_len = source.len();
+    // Tokenizing is eager (as it always was); a tokenize failure (e.g. an
+    // unterminated string) is stashed and yielded as the iterator's only
+    // item, same as a parse failure partway through.
+    let mut state = match tokenize(source) {
+        Ok(tokens) => Ok(Parser {
+            tokens,
+            pos: 0,
+            source_len,
+        }),
+        Err(e) => Err(Some(e)),
+    };
+
+    std::iter::from_fn(move || match &mut state {
+        Ok(parser) => match parser.next_expr() {
+            Ok(Some(expr)) => Some(Ok(expr)),
+            Ok(None) => None,
+            Err(e) => {
+                let err = e.clone();
+                state = Err(None);
+                Some(Err(err))
+            }
+        },
+        Err(pending) => pending.take().map(Err),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unterminated_string_is_incomplete() {
+        let err = parse_spanned("\"hello world").next().unwrap().unwrap_err();
+        assert!(err.is_incomplete());
+    }
+
+    #[test]
+    fn unterminated_list_is_incomplete() {
+        let err = parse_spanned("(foo").next().unwrap().unwrap_err();
+        assert!(err.is_incomplete());
+    }
+
+    #[test]
+    fn terminated_string_parses_as_a_string() {
+        let value = parse_spanned("\"hello world\"")
+            .next()
+            .unwrap()
+            .unwrap()
+            .0;
+        assert_eq!(value, Value::String("hello world".to_string()));
+    }
+}
@@ -0,0 +1,228 @@
+//! Interactive REPL built on top of `rustyline`, gated behind the `repl`
+//! feature so embedding the core interpreter doesn't pull in a
+//! line-editing dependency.
+
+use crate::model::{Env, Value};
+use crate::utils::format_caret;
+use crate::{default_env, eval_block_spanned_verbose, parse_spanned, ParseError};
+use rustyline::error::ReadlineError;
+use rustyline::{DefaultEditor, Editor};
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// Secondary prompt shown while an expression's parentheses are still
+/// unbalanced, so multi-line forms (like a `defun`) can be typed or
+/// pasted across several lines.
+const CONTINUATION_PROMPT: &str = ".. ";
+
+/// Parses every top-level expression in `source` along with its span,
+/// stopping at the first error instead of silently dropping it.
+fn try_parse_all(source: &str) -> Result<Vec<(Value, Range<usize>)>, ParseError> {
+    parse_spanned(source).collect()
+}
+
+/// What happened when [`accumulate_until_complete`] asked for another
+/// line of input to continue an unbalanced form.
+enum LineResult {
+    /// Another line was read; keep accumulating.
+    More(String),
+    /// Ctrl-D / EOF while a form was still unbalanced.
+    Eof,
+    /// Any other readline failure (e.g. Ctrl-C); abandon the buffer.
+    OtherError,
+}
+
+/// Outcome of accumulating lines into `buffer` until it parses as a
+/// complete (or definitely invalid) block.
+enum ContinuationResult {
+    /// The final buffer, and the complete set of top-level expressions
+    /// it parsed into.
+    Complete(String, Vec<(Value, Range<usize>)>),
+    /// EOF arrived while still waiting on a continuation line.
+    Eof,
+    /// A non-EOF readline error arrived while waiting on a continuation line.
+    Abandoned,
+    /// The buffer failed to parse for a reason other than being
+    /// incomplete - it's a real error.
+    ParseError(String, ParseError),
+}
+
+/// Repeatedly tries to parse `buffer` as a complete block, pulling
+/// another line from `next_line` each time the parse only fails because
+/// the input is unbalanced so far (e.g. an open paren with no match
+/// yet). Pure with respect to the editor: callers supply line input via
+/// `next_line` instead of reading from `rustyline` directly, which keeps
+/// this accumulation logic unit-testable.
+fn accumulate_until_complete(
+    mut buffer: String,
+    mut next_line: impl FnMut() -> LineResult,
+) -> ContinuationResult {
+    loop {
+        match try_parse_all(&buffer) {
+            Ok(exprs) => return ContinuationResult::Complete(buffer, exprs),
+            Err(e) if e.is_incomplete() => match next_line() {
+                LineResult::More(more) => {
+                    buffer.push('\n');
+                    buffer.push_str(&more);
+                }
+                LineResult::Eof => return ContinuationResult::Eof,
+                LineResult::OtherError => return ContinuationResult::Abandoned,
+            },
+            Err(e) => return ContinuationResult::ParseError(buffer, e),
+        }
+    }
+}
+
+/// Configuration for [`start_repl`].
+pub struct ReplConfig {
+    /// Prompt printed before each line of input.
+    pub prompt: String,
+    /// Path history is loaded from and saved to; `None` disables persistence.
+    pub history_path: Option<String>,
+    /// Environment to evaluate input against; defaults to [`default_env`].
+    pub env: Option<Env>,
+    /// When true, prints the read-phase AST of each form before
+    /// evaluating it, and the eval-phase result (with its type) after.
+    pub verbose: bool,
+}
+
+impl Default for ReplConfig {
+    fn default() -> Self {
+        ReplConfig {
+            prompt: "> ".to_string(),
+            history_path: Some(".rust_lisp_history".to_string()),
+            env: None,
+            verbose: false,
+        }
+    }
+}
+
+/// Starts a REPL prompt at stdin/stdout using a `rustyline` editor for
+/// line-editing, history and Ctrl-C/Ctrl-D handling. **This will block
+/// the current thread.**
+pub fn start_repl(config: Option<ReplConfig>) {
+    let config = config.unwrap_or_default();
+    let env_rc = Rc::new(RefCell::new(config.env.unwrap_or_else(default_env)));
+
+    let mut editor: DefaultEditor = Editor::new().expect("failed to start the line editor");
+    if let Some(history_path) = &config.history_path {
+        let _ = editor.load_history(history_path);
+    }
+
+    'repl: loop {
+        match editor.readline(&config.prompt) {
+            Ok(line) => {
+                let _ = editor.add_history_entry(line.as_str());
+
+                let result = accumulate_until_complete(line, || match editor.readline(CONTINUATION_PROMPT) {
+                    Ok(more) => {
+                        let _ = editor.add_history_entry(more.as_str());
+                        LineResult::More(more)
+                    }
+                    Err(ReadlineError::Eof) => LineResult::Eof,
+                    Err(_) => LineResult::OtherError,
+                });
+
+                match result {
+                    ContinuationResult::Complete(buffer, exprs) => {
+                        match eval_block_spanned_verbose(env_rc.clone(), exprs.into_iter(), config.verbose) {
+                            Ok(val) => println!("{}", val),
+                            Err(e) => {
+                                if let Some(span) = e.span.clone() {
+                                    println!("{}", format_caret(&buffer, span));
+                                }
+                                println!("{}", e);
+                            }
+                        }
+                    }
+                    // Ctrl-D / EOF while continuing a multi-line form: exit
+                    // cleanly through the same path as a top-level EOF, so
+                    // history still gets saved below.
+                    ContinuationResult::Eof => {
+                        println!("Goodbye!");
+                        break 'repl;
+                    }
+                    ContinuationResult::Abandoned => {}
+                    ContinuationResult::ParseError(buffer, e) => {
+                        println!("{}", format_caret(&buffer, e.span()));
+                        println!("{}", e);
+                    }
+                }
+            }
+            // Ctrl-C: abort the current line and keep looping.
+            Err(ReadlineError::Interrupted) => continue,
+            // Ctrl-D / EOF: exit cleanly.
+            Err(ReadlineError::Eof) => {
+                println!("Goodbye!");
+                break;
+            }
+            Err(e) => {
+                println!("error reading input: {}", e);
+                break;
+            }
+        }
+    }
+
+    if let Some(history_path) = &config.history_path {
+        let _ = editor.save_history(history_path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Drives [`accumulate_until_complete`] from a fixed script of lines,
+    /// standing in for the editor so the buffering logic can be tested
+    /// without a real `rustyline::Editor`.
+    fn accumulate(initial: &str, continuation_lines: &[&str]) -> ContinuationResult {
+        let mut remaining = continuation_lines.iter();
+        accumulate_until_complete(initial.to_string(), || match remaining.next() {
+            Some(line) => LineResult::More(line.to_string()),
+            None => LineResult::Eof,
+        })
+    }
+
+    #[test]
+    fn a_balanced_line_needs_no_continuation() {
+        let result = accumulate("(+ 1 2)", &[]);
+        assert!(matches!(result, ContinuationResult::Complete(_, _)));
+    }
+
+    #[test]
+    fn an_unbalanced_form_is_completed_across_continuation_lines() {
+        let result = accumulate("(defun f (x)", &["(+ x 1))"]);
+        match result {
+            ContinuationResult::Complete(buffer, exprs) => {
+                assert_eq!(buffer, "(defun f (x)\n(+ x 1))");
+                assert_eq!(exprs.len(), 1);
+            }
+            _ => panic!("expected the continued form to parse"),
+        }
+    }
+
+    #[test]
+    fn eof_while_continuing_an_unbalanced_form_is_reported_as_eof() {
+        let result = accumulate("(defun f (x)", &[]);
+        assert!(matches!(result, ContinuationResult::Eof));
+    }
+
+    #[test]
+    fn a_non_incomplete_parse_error_is_not_retried() {
+        let result = accumulate(")", &["(ignored)"]);
+        match result {
+            ContinuationResult::ParseError(buffer, e) => {
+                assert_eq!(buffer, ")");
+                assert!(!e.is_incomplete());
+            }
+            _ => panic!("expected an immediate parse error"),
+        }
+    }
+
+    #[test]
+    fn a_readline_error_other_than_eof_abandons_the_buffer() {
+        let result = accumulate_until_complete("(defun f (x)".to_string(), || LineResult::OtherError);
+        assert!(matches!(result, ContinuationResult::Abandoned));
+    }
+}
@@ -0,0 +1,74 @@
+//! Small helpers shared across the parser, interpreter and REPL that
+//! don't belong to any one of them.
+
+use crate::model::Value;
+use std::ops::Range;
+
+/// Renders `value` the way verbose evaluation reports it: the printed
+/// value followed by its type name in parentheses.
+pub fn describe(value: &Value) -> String {
+    format!("{} ({})", value, value.type_name())
+}
+
+/// Renders the line of `source` that `span` falls in, followed by a
+/// caret row underlining the span (minimum width 1), for diagnostics
+/// that need to point back at the offending source text.
+pub fn format_caret(source: &str, span: Range<usize>) -> String {
+    let line_start = source[..span.start.min(source.len())]
+        .rfind('\n')
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let line_end = source[line_start..]
+        .find('\n')
+        .map(|i| line_start + i)
+        .unwrap_or(source.len());
+    let line = &source[line_start..line_end];
+
+    let col = span.start.saturating_sub(line_start).min(line.len());
+    let width = span.end.saturating_sub(span.start).max(1);
+    let caret_len = width.min(line.len().saturating_sub(col).max(1));
+
+    format!("{}\n{}{}", line, " ".repeat(col), "^".repeat(caret_len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Value;
+
+    #[test]
+    fn describe_includes_the_type_name() {
+        assert_eq!(describe(&Value::Int(3)), "3 (int)");
+        assert_eq!(describe(&Value::Bool(true)), "#t (bool)");
+    }
+
+    #[test]
+    fn format_caret_underlines_a_mid_line_span() {
+        let rendered = format_caret("(foo bar)", 5..8);
+        assert_eq!(rendered, "(foo bar)\n     ^^^");
+    }
+
+    #[test]
+    fn format_caret_on_a_later_line_only_shows_that_line() {
+        let rendered = format_caret("(foo\n(bar))", 5..10);
+        assert_eq!(rendered, "(bar))\n^^^^^");
+    }
+
+    #[test]
+    fn format_caret_widens_a_zero_width_span_to_one_caret() {
+        let rendered = format_caret("foo", 3..3);
+        assert_eq!(rendered, "foo\n   ^");
+    }
+
+    #[test]
+    fn format_caret_at_eof_clamps_into_the_line() {
+        let rendered = format_caret("(foo", 4..4);
+        assert_eq!(rendered, "(foo\n    ^");
+    }
+
+    #[test]
+    fn format_caret_clamps_a_span_that_overruns_the_line() {
+        let rendered = format_caret("(foo\n(bar", 1..20);
+        assert_eq!(rendered, "(foo\n ^^^");
+    }
+}